@@ -0,0 +1,105 @@
+use parquet_format_safe::{
+    AesGcmCtrV1 as TAesGcmCtrV1, AesGcmV1 as TAesGcmV1,
+    EncryptionAlgorithm as TEncryptionAlgorithm,
+};
+#[cfg(feature = "serde_types")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// The AES-GCM and AES-GCM-CTR parameters shared by both Parquet modular encryption algorithms.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde_types", derive(Deserialize, Serialize))]
+pub struct AesGcmParameters {
+    /// AAD prefix string shared by all files in the same encryption context, when used.
+    pub aad_prefix: Option<Vec<u8>>,
+    /// Unique file identifier, part of the AAD suffix used for file encryption.
+    pub aad_file_unique: Option<Vec<u8>>,
+    /// Whether the readers should expect the `aad_prefix` to be supplied out of band (e.g. by
+    /// configuration) rather than stored in the file.
+    pub supply_aad_prefix: Option<bool>,
+}
+
+/// The encryption algorithm a Parquet file was encrypted with, and its associated parameters.
+///
+/// See the [Parquet encryption spec](https://github.com/apache/parquet-format/blob/master/Encryption.md)
+/// for the meaning of each field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_types", derive(Deserialize, Serialize))]
+pub enum EncryptionAlgorithm {
+    /// AES-GCM, encrypting the whole file with a single key.
+    AesGcmV1(AesGcmParameters),
+    /// AES-GCM-CTR, allowing column chunks to be encrypted independently.
+    AesGcmCtrV1(AesGcmParameters),
+}
+
+impl EncryptionAlgorithm {
+    /// Deserializes [`parquet_format_safe::EncryptionAlgorithm`] into this enum.
+    pub fn try_from_thrift(algorithm: TEncryptionAlgorithm) -> Result<Self, Error> {
+        Ok(match algorithm {
+            TEncryptionAlgorithm::AESGCMV1(v) => Self::AesGcmV1(AesGcmParameters {
+                aad_prefix: v.aad_prefix,
+                aad_file_unique: v.aad_file_unique,
+                supply_aad_prefix: v.supply_aad_prefix,
+            }),
+            TEncryptionAlgorithm::AESGCMCTRV1(v) => Self::AesGcmCtrV1(AesGcmParameters {
+                aad_prefix: v.aad_prefix,
+                aad_file_unique: v.aad_file_unique,
+                supply_aad_prefix: v.supply_aad_prefix,
+            }),
+        })
+    }
+
+    /// Serializes itself to thrift's [`parquet_format_safe::EncryptionAlgorithm`].
+    pub fn into_thrift(self) -> TEncryptionAlgorithm {
+        match self {
+            Self::AesGcmV1(p) => TEncryptionAlgorithm::AESGCMV1(TAesGcmV1 {
+                aad_prefix: p.aad_prefix,
+                aad_file_unique: p.aad_file_unique,
+                supply_aad_prefix: p.supply_aad_prefix,
+            }),
+            Self::AesGcmCtrV1(p) => TEncryptionAlgorithm::AESGCMCTRV1(TAesGcmCtrV1 {
+                aad_prefix: p.aad_prefix,
+                aad_file_unique: p.aad_file_unique,
+                supply_aad_prefix: p.supply_aad_prefix,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parameters() -> AesGcmParameters {
+        AesGcmParameters {
+            aad_prefix: Some(vec![1, 2, 3]),
+            aad_file_unique: Some(vec![4, 5, 6]),
+            supply_aad_prefix: Some(true),
+        }
+    }
+
+    #[test]
+    fn aes_gcm_v1_round_trips_through_thrift() {
+        let algorithm = EncryptionAlgorithm::AesGcmV1(parameters());
+
+        let result = EncryptionAlgorithm::try_from_thrift(algorithm.clone().into_thrift()).unwrap();
+        assert_eq!(result, algorithm);
+    }
+
+    #[test]
+    fn aes_gcm_ctr_v1_round_trips_through_thrift() {
+        let algorithm = EncryptionAlgorithm::AesGcmCtrV1(parameters());
+
+        let result = EncryptionAlgorithm::try_from_thrift(algorithm.clone().into_thrift()).unwrap();
+        assert_eq!(result, algorithm);
+    }
+
+    #[test]
+    fn default_parameters_round_trip() {
+        let algorithm = EncryptionAlgorithm::AesGcmV1(AesGcmParameters::default());
+
+        let result = EncryptionAlgorithm::try_from_thrift(algorithm.clone().into_thrift()).unwrap();
+        assert_eq!(result, algorithm);
+    }
+}
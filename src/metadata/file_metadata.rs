@@ -1,6 +1,10 @@
 use crate::{error::Error, metadata::get_sort_order};
 
-use super::{column_order::ColumnOrder, schema_descriptor::SchemaDescriptor, RowGroupMetaData};
+use super::{
+    column_order::ColumnOrder, encryption::EncryptionAlgorithm,
+    schema_descriptor::SchemaDescriptor, RowGroupMetaData,
+};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use parquet_format_safe::ColumnOrder as TColumnOrder;
 #[cfg(feature = "serde_types")]
 use serde::{Deserialize, Serialize};
@@ -91,9 +95,35 @@ pub struct FileMetaData {
     /// When `None` is returned, there are no column orders available, and each column
     /// should be assumed to have undefined (legacy) column order.
     pub column_orders: Option<Vec<ColumnOrder>>,
+    /// The algorithm this file was encrypted with, if any.
+    pub encryption_algorithm: Option<EncryptionAlgorithm>,
+    /// Key metadata for the footer signing key, if any.
+    pub footer_signing_key_metadata: Option<Vec<u8>>,
 }
 
 impl FileMetaData {
+    /// Creates a new [`FileMetaData`] from its constituent parts, with no key-value metadata and
+    /// no column orders, encryption algorithm, or footer signing key metadata set.
+    pub fn new(
+        version: i32,
+        schema_descr: SchemaDescriptor,
+        num_rows: usize,
+        row_groups: Vec<RowGroupMetaData>,
+        created_by: Option<String>,
+    ) -> Self {
+        Self {
+            version,
+            num_rows,
+            created_by,
+            row_groups,
+            key_value_metadata: None,
+            schema_descr,
+            column_orders: None,
+            encryption_algorithm: None,
+            footer_signing_key_metadata: None,
+        }
+    }
+
     /// Returns the [`SchemaDescriptor`] that describes schema of this file.
     pub fn schema(&self) -> &SchemaDescriptor {
         &self.schema_descr
@@ -104,6 +134,42 @@ impl FileMetaData {
         &self.key_value_metadata
     }
 
+    /// Returns the value associated with `key` in this file's key-value metadata, if present.
+    pub fn get_key_value(&self, key: &str) -> Option<&str> {
+        find_key_value(&self.key_value_metadata, key)
+    }
+
+    /// Returns the raw Arrow IPC schema message stashed by Arrow writers under the
+    /// `ARROW:schema` key of this file's key-value metadata, if present.
+    ///
+    /// The value is base64-decoded and, if it carries the 8-byte IPC continuation and length
+    /// prefix (indicated by a leading `0xFFFFFFFF`), that prefix is stripped. Parsing the
+    /// resulting IPC message into an Arrow schema is left to downstream consumers (e.g. the
+    /// `arrow2` crate) so that this crate does not need to depend on Arrow.
+    pub fn arrow_schema_ipc_bytes(&self) -> Result<Option<Vec<u8>>, Error> {
+        self.get_key_value("ARROW:schema")
+            .map(decode_arrow_schema_ipc)
+            .transpose()
+    }
+
+    /// Appends a new key-value pair to this file's key-value metadata, even if `key` is already
+    /// present. Use [`FileMetaData::set_key_value`] to replace an existing entry instead.
+    pub fn insert_key_value(&mut self, key: String, value: Option<String>) {
+        insert_key_value(&mut self.key_value_metadata, key, value);
+    }
+
+    /// Replaces the value associated with `key` in this file's key-value metadata, or appends a
+    /// new entry if `key` is not already present.
+    pub fn set_key_value(&mut self, key: String, value: Option<String>) {
+        set_key_value(&mut self.key_value_metadata, key, value);
+    }
+
+    /// Removes all entries associated with `key` from this file's key-value metadata, returning
+    /// their values.
+    pub fn remove_key_value(&mut self, key: &str) -> Vec<Option<String>> {
+        remove_key_value(&mut self.key_value_metadata, key)
+    }
+
     /// Returns column order for `i`th column in this file.
     /// If column orders are not available, returns undefined (legacy) column order.
     pub fn column_order(&self, i: usize) -> ColumnOrder {
@@ -127,6 +193,11 @@ impl FileMetaData {
             .column_orders
             .map(|orders| parse_column_orders(&orders, &schema_descr));
 
+        let encryption_algorithm = metadata
+            .encryption_algorithm
+            .map(EncryptionAlgorithm::try_from_thrift)
+            .transpose()?;
+
         Ok(FileMetaData {
             version: metadata.version,
             num_rows: metadata.num_rows.try_into()?,
@@ -135,12 +206,16 @@ impl FileMetaData {
             key_value_metadata: metadata.key_value_metadata,
             schema_descr,
             column_orders,
+            encryption_algorithm,
+            footer_signing_key_metadata: metadata.footer_signing_key_metadata,
         })
     }
 
     /// Serializes itself to thrift's [`parquet_format_safe::FileMetaData`].
-    pub fn into_thrift(self) -> parquet_format_safe::FileMetaData {
-        parquet_format_safe::FileMetaData {
+    pub fn into_thrift(self) -> Result<parquet_format_safe::FileMetaData, Error> {
+        let column_orders = self.column_orders.map(column_orders_into_thrift).transpose()?;
+
+        Ok(parquet_format_safe::FileMetaData {
             version: self.version,
             schema: self.schema_descr.into_thrift(),
             num_rows: self.num_rows as i64,
@@ -151,10 +226,12 @@ impl FileMetaData {
                 .collect(),
             key_value_metadata: self.key_value_metadata,
             created_by: self.created_by,
-            column_orders: None, // todo
-            encryption_algorithm: None,
-            footer_signing_key_metadata: None,
-        }
+            column_orders,
+            encryption_algorithm: self
+                .encryption_algorithm
+                .map(EncryptionAlgorithm::into_thrift),
+            footer_signing_key_metadata: self.footer_signing_key_metadata,
+        })
     }
 }
 
@@ -179,3 +256,221 @@ fn parse_column_orders(
         })
         .collect()
 }
+
+/// Serializes [`ColumnOrder`] to its thrift representation (one per leaf column, in schema
+/// order).
+///
+/// Thrift only has a representation for [`ColumnOrder::TypeDefinedOrder`]. `ColumnOrder::Undefined`
+/// is a legitimate, ordinary value of this public field (e.g. a metadata-only rewriter that
+/// doesn't know a column's order), so rather than panicking on it, this returns an error that
+/// callers can handle — for instance by clearing `column_orders` to `None` before serializing.
+fn column_orders_into_thrift(orders: Vec<ColumnOrder>) -> Result<Vec<TColumnOrder>, Error> {
+    orders
+        .into_iter()
+        .map(|order| match order {
+            ColumnOrder::TypeDefinedOrder(_) => {
+                Ok(TColumnOrder::TYPEORDER(parquet_format_safe::TypeDefinedOrder {}))
+            }
+            ColumnOrder::Undefined => Err(Error::oos(
+                "FileMetaData::into_thrift cannot serialize ColumnOrder::Undefined: \
+                 thrift has no representation for it",
+            )),
+        })
+        .collect()
+}
+
+/// Appends a new key-value pair to `metadata`, even if `key` is already present.
+fn insert_key_value(metadata: &mut Option<Vec<KeyValue>>, key: String, value: Option<String>) {
+    metadata.get_or_insert_with(Vec::new).push(KeyValue { key, value });
+}
+
+/// Replaces the value associated with `key` in `metadata`, or appends a new entry if `key` is
+/// not already present.
+fn set_key_value(metadata: &mut Option<Vec<KeyValue>>, key: String, value: Option<String>) {
+    if let Some(entries) = metadata.as_mut() {
+        if let Some(entry) = entries.iter_mut().find(|kv| kv.key == key) {
+            entry.value = value;
+            return;
+        }
+    }
+    insert_key_value(metadata, key, value);
+}
+
+/// Removes all entries associated with `key` from `metadata`, returning their values.
+fn remove_key_value(metadata: &mut Option<Vec<KeyValue>>, key: &str) -> Vec<Option<String>> {
+    let entries = match metadata.as_mut() {
+        Some(entries) => entries,
+        None => return Vec::new(),
+    };
+
+    let mut removed = Vec::new();
+    entries.retain(|kv| {
+        if kv.key == key {
+            removed.push(kv.value.clone());
+            false
+        } else {
+            true
+        }
+    });
+    removed
+}
+
+/// Returns the value associated with `key` in `metadata`, if present.
+fn find_key_value<'a>(metadata: &'a Option<Vec<KeyValue>>, key: &str) -> Option<&'a str> {
+    metadata
+        .as_ref()?
+        .iter()
+        .find(|kv| kv.key == key)
+        .and_then(|kv| kv.value.as_deref())
+}
+
+/// Decodes a base64-encoded, optionally length-prefixed Arrow IPC schema message (the format
+/// Arrow writers store under the `ARROW:schema` key), stripping the 8-byte continuation/length
+/// prefix when present.
+fn decode_arrow_schema_ipc(encoded: &str) -> Result<Vec<u8>, Error> {
+    let decoded = STANDARD
+        .decode(encoded)
+        .map_err(|e| Error::oos(format!("ARROW:schema key is not valid base64: {e}")))?;
+
+    if decoded.len() >= 8 && decoded[..4] == [0xff, 0xff, 0xff, 0xff] {
+        Ok(decoded[8..].to_vec())
+    } else {
+        Ok(decoded)
+    }
+}
+
+#[cfg(test)]
+mod column_order_tests {
+    use super::*;
+    use crate::metadata::column_order::SortOrder;
+
+    #[test]
+    fn type_defined_order_serializes_to_thrift_type_order() {
+        let orders = vec![
+            ColumnOrder::TypeDefinedOrder(SortOrder::Signed),
+            ColumnOrder::TypeDefinedOrder(SortOrder::Unsigned),
+        ];
+
+        let thrift = column_orders_into_thrift(orders).unwrap();
+        assert_eq!(
+            thrift,
+            vec![
+                TColumnOrder::TYPEORDER(parquet_format_safe::TypeDefinedOrder {}),
+                TColumnOrder::TYPEORDER(parquet_format_safe::TypeDefinedOrder {}),
+            ]
+        );
+    }
+
+    #[test]
+    fn undefined_order_is_an_error_instead_of_mis_serializing() {
+        assert!(column_orders_into_thrift(vec![ColumnOrder::Undefined]).is_err());
+    }
+}
+
+#[cfg(test)]
+mod key_value_mutation_tests {
+    use super::*;
+
+    #[test]
+    fn insert_key_value_appends_even_if_key_exists() {
+        let mut metadata = None;
+        insert_key_value(&mut metadata, "a".to_string(), Some("1".to_string()));
+        insert_key_value(&mut metadata, "a".to_string(), Some("2".to_string()));
+
+        assert_eq!(
+            metadata.unwrap().iter().filter(|kv| kv.key == "a").count(),
+            2
+        );
+    }
+
+    #[test]
+    fn set_key_value_replaces_existing_entry() {
+        let mut metadata = None;
+        insert_key_value(&mut metadata, "a".to_string(), Some("1".to_string()));
+        set_key_value(&mut metadata, "a".to_string(), Some("2".to_string()));
+
+        let entries = metadata.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].value, Some("2".to_string()));
+    }
+
+    #[test]
+    fn set_key_value_inserts_when_absent() {
+        let mut metadata = None;
+        set_key_value(&mut metadata, "a".to_string(), Some("1".to_string()));
+
+        assert_eq!(metadata.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn remove_key_value_removes_all_matches_and_returns_their_values() {
+        let mut metadata = None;
+        insert_key_value(&mut metadata, "a".to_string(), Some("1".to_string()));
+        insert_key_value(&mut metadata, "b".to_string(), Some("2".to_string()));
+        insert_key_value(&mut metadata, "a".to_string(), None);
+
+        let removed = remove_key_value(&mut metadata, "a");
+        assert_eq!(removed, vec![Some("1".to_string()), None]);
+
+        let entries = metadata.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].key, "b");
+    }
+
+    #[test]
+    fn remove_key_value_on_absent_metadata_returns_empty() {
+        let mut metadata = None;
+        assert_eq!(remove_key_value(&mut metadata, "a"), Vec::<Option<String>>::new());
+    }
+}
+
+#[cfg(test)]
+mod key_value_tests {
+    use super::*;
+
+    fn metadata(pairs: &[(&str, &str)]) -> Option<Vec<KeyValue>> {
+        Some(
+            pairs
+                .iter()
+                .map(|(key, value)| KeyValue {
+                    key: key.to_string(),
+                    value: Some(value.to_string()),
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn find_key_value_returns_none_for_missing_key() {
+        let metadata = metadata(&[("a", "1")]);
+        assert_eq!(find_key_value(&metadata, "ARROW:schema"), None);
+        assert_eq!(find_key_value(&None, "a"), None);
+    }
+
+    #[test]
+    fn find_key_value_returns_the_matching_value() {
+        let metadata = metadata(&[("a", "1"), ("ARROW:schema", "c2NoZW1h")]);
+        assert_eq!(find_key_value(&metadata, "ARROW:schema"), Some("c2NoZW1h"));
+    }
+
+    #[test]
+    fn decode_arrow_schema_ipc_strips_length_prefix_when_present() {
+        let mut prefixed = vec![0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0];
+        prefixed.extend_from_slice(b"schema");
+        let encoded = STANDARD.encode(&prefixed);
+
+        assert_eq!(decode_arrow_schema_ipc(&encoded).unwrap(), b"schema");
+    }
+
+    #[test]
+    fn decode_arrow_schema_ipc_passes_through_unprefixed_payload() {
+        let encoded = STANDARD.encode(b"schema");
+
+        assert_eq!(decode_arrow_schema_ipc(&encoded).unwrap(), b"schema");
+    }
+
+    #[test]
+    fn decode_arrow_schema_ipc_rejects_invalid_base64() {
+        assert!(decode_arrow_schema_ipc("not valid base64!!").is_err());
+    }
+}
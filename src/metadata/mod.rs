@@ -0,0 +1,9 @@
+mod encryption;
+mod file_metadata;
+mod page_index;
+mod row_group_metadata;
+
+pub use encryption::{AesGcmParameters, EncryptionAlgorithm};
+pub use file_metadata::{FileMetaData, KeyValue};
+pub use page_index::{BoundaryOrder, ColumnIndex, OffsetIndex};
+pub use row_group_metadata::RowGroupMetaData;
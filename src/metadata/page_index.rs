@@ -0,0 +1,230 @@
+//! The Parquet page index: [`ColumnIndex`] and [`OffsetIndex`], stored near the footer and used
+//! for page-level predicate pushdown and row skipping.
+//!
+//! Callers parse these per column chunk and attach them (e.g. as `Option<Vec<ColumnIndex>>` /
+//! `Option<Vec<OffsetIndex>>`, one entry per column) to the owning `RowGroupMetaData`.
+
+use parquet_format_safe::{
+    BoundaryOrder as TBoundaryOrder, ColumnIndex as TColumnIndex, OffsetIndex as TOffsetIndex,
+    PageLocation as TPageLocation,
+};
+#[cfg(feature = "serde_types")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// The relative ordering of the min/max values of the pages in a [`ColumnIndex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_types", derive(Deserialize, Serialize))]
+pub enum BoundaryOrder {
+    /// The values are not ordered.
+    Unordered,
+    /// The values are ordered in ascending order.
+    Ascending,
+    /// The values are ordered in descending order.
+    Descending,
+}
+
+impl BoundaryOrder {
+    fn from_thrift(order: TBoundaryOrder) -> Self {
+        match order {
+            TBoundaryOrder::UNORDERED => Self::Unordered,
+            TBoundaryOrder::ASCENDING => Self::Ascending,
+            TBoundaryOrder::DESCENDING => Self::Descending,
+        }
+    }
+
+    fn into_thrift(self) -> TBoundaryOrder {
+        match self {
+            Self::Unordered => TBoundaryOrder::UNORDERED,
+            Self::Ascending => TBoundaryOrder::ASCENDING,
+            Self::Descending => TBoundaryOrder::DESCENDING,
+        }
+    }
+}
+
+/// The Parquet page index for a single column chunk: per-page min/max statistics that let
+/// readers skip whole pages without decoding them.
+///
+/// Every field is indexed by page, in the order the pages appear in the column chunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_types", derive(Deserialize, Serialize))]
+pub struct ColumnIndex {
+    /// Whether page `i` consists entirely of null values.
+    pub null_pages: Vec<bool>,
+    /// The minimum value of page `i`, or `None` if the page has no meaningful min/max (e.g. an
+    /// all-null page).
+    pub min_values: Vec<Option<Vec<u8>>>,
+    /// The maximum value of page `i`, or `None` if the page has no meaningful min/max (e.g. an
+    /// all-null page).
+    pub max_values: Vec<Option<Vec<u8>>>,
+    /// The order in which `min_values`/`max_values` are sorted across pages.
+    pub boundary_order: BoundaryOrder,
+    /// The number of null values in page `i`, when available.
+    pub null_counts: Option<Vec<i64>>,
+}
+
+impl ColumnIndex {
+    /// Deserializes [`parquet_format_safe::ColumnIndex`] into this struct.
+    ///
+    /// Returns an error if `min_values`, `max_values` or `null_counts` (when present) don't have
+    /// one entry per page in `null_pages`, as required by the page index spec.
+    pub fn try_from_thrift(index: TColumnIndex) -> Result<Self, Error> {
+        let TColumnIndex {
+            null_pages,
+            min_values,
+            max_values,
+            boundary_order,
+            null_counts,
+        } = index;
+
+        let num_pages = null_pages.len();
+        if min_values.len() != num_pages || max_values.len() != num_pages {
+            return Err(Error::oos(
+                "ColumnIndex's null_pages, min_values and max_values must have the same length",
+            ));
+        }
+        if let Some(null_counts) = &null_counts {
+            if null_counts.len() != num_pages {
+                return Err(Error::oos(
+                    "ColumnIndex's null_counts must have the same length as null_pages",
+                ));
+            }
+        }
+
+        let to_values = |values: Vec<Vec<u8>>| {
+            null_pages
+                .iter()
+                .zip(values)
+                .map(|(is_null, value)| if *is_null { None } else { Some(value) })
+                .collect()
+        };
+
+        Ok(Self {
+            min_values: to_values(min_values),
+            max_values: to_values(max_values),
+            null_pages,
+            boundary_order: BoundaryOrder::from_thrift(boundary_order),
+            null_counts,
+        })
+    }
+
+    /// Serializes itself to thrift's [`parquet_format_safe::ColumnIndex`].
+    pub fn into_thrift(self) -> TColumnIndex {
+        let null_pages: Vec<bool> = self.min_values.iter().map(Option::is_none).collect();
+
+        TColumnIndex {
+            null_pages,
+            min_values: self
+                .min_values
+                .into_iter()
+                .map(Option::unwrap_or_default)
+                .collect(),
+            max_values: self
+                .max_values
+                .into_iter()
+                .map(Option::unwrap_or_default)
+                .collect(),
+            boundary_order: self.boundary_order.into_thrift(),
+            null_counts: self.null_counts,
+        }
+    }
+}
+
+/// The Parquet offset index for a single column chunk: the byte offset, compressed size and
+/// first row index of every page, letting readers locate and random-access pages directly.
+///
+/// Every field is indexed by page, in the order the pages appear in the column chunk.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde_types", derive(Deserialize, Serialize))]
+pub struct OffsetIndex {
+    /// The byte offset of page `i` in the file.
+    pub offsets: Vec<i64>,
+    /// The compressed size, in bytes, of page `i`, including its header.
+    pub compressed_page_sizes: Vec<i32>,
+    /// The row index of the first row of page `i`, relative to the start of the column chunk.
+    pub first_row_indices: Vec<i64>,
+}
+
+impl OffsetIndex {
+    /// Deserializes [`parquet_format_safe::OffsetIndex`] into this struct.
+    pub fn try_from_thrift(index: TOffsetIndex) -> Result<Self, Error> {
+        let mut offsets = Vec::with_capacity(index.page_locations.len());
+        let mut compressed_page_sizes = Vec::with_capacity(index.page_locations.len());
+        let mut first_row_indices = Vec::with_capacity(index.page_locations.len());
+        for location in index.page_locations {
+            offsets.push(location.offset);
+            compressed_page_sizes.push(location.compressed_page_size);
+            first_row_indices.push(location.first_row_index);
+        }
+
+        Ok(Self {
+            offsets,
+            compressed_page_sizes,
+            first_row_indices,
+        })
+    }
+
+    /// Serializes itself to thrift's [`parquet_format_safe::OffsetIndex`].
+    pub fn into_thrift(self) -> TOffsetIndex {
+        let page_locations = self
+            .offsets
+            .into_iter()
+            .zip(self.compressed_page_sizes)
+            .zip(self.first_row_indices)
+            .map(
+                |((offset, compressed_page_size), first_row_index)| TPageLocation {
+                    offset,
+                    compressed_page_size,
+                    first_row_index,
+                },
+            )
+            .collect();
+
+        TOffsetIndex { page_locations }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_index_round_trips_through_thrift() {
+        let index = ColumnIndex {
+            null_pages: vec![false, true, false],
+            min_values: vec![Some(vec![1]), None, Some(vec![3])],
+            max_values: vec![Some(vec![2]), None, Some(vec![4])],
+            boundary_order: BoundaryOrder::Ascending,
+            null_counts: Some(vec![0, 5, 0]),
+        };
+
+        let result = ColumnIndex::try_from_thrift(index.clone().into_thrift()).unwrap();
+        assert_eq!(result, index);
+    }
+
+    #[test]
+    fn column_index_rejects_mismatched_lengths() {
+        let index = TColumnIndex {
+            null_pages: vec![false, false],
+            min_values: vec![vec![1]],
+            max_values: vec![vec![1], vec![2]],
+            boundary_order: TBoundaryOrder::UNORDERED,
+            null_counts: None,
+        };
+
+        assert!(ColumnIndex::try_from_thrift(index).is_err());
+    }
+
+    #[test]
+    fn offset_index_round_trips_through_thrift() {
+        let index = OffsetIndex {
+            offsets: vec![0, 100, 250],
+            compressed_page_sizes: vec![100, 150, 80],
+            first_row_indices: vec![0, 50, 120],
+        };
+
+        let result = OffsetIndex::try_from_thrift(index.clone().into_thrift()).unwrap();
+        assert_eq!(result, index);
+    }
+}
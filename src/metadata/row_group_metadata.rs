@@ -0,0 +1,132 @@
+use parquet_format_safe::RowGroup as TRowGroup;
+#[cfg(feature = "serde_types")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+use super::{
+    column_chunk_metadata::ColumnChunkMetaData,
+    page_index::{ColumnIndex, OffsetIndex},
+    schema_descriptor::SchemaDescriptor,
+};
+
+/// Metadata for a row group, as stored in a Parquet file's footer.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde_types", derive(Deserialize, Serialize))]
+pub struct RowGroupMetaData {
+    columns: Vec<ColumnChunkMetaData>,
+    num_rows: usize,
+    total_byte_size: usize,
+    /// This row group's [`ColumnIndex`], one entry per column in schema order.
+    ///
+    /// The Parquet `RowGroup` thrift struct doesn't embed the page index itself (only the byte
+    /// offset/length of each column's index), so this is always `None` right after
+    /// [`RowGroupMetaData::try_from_thrift`]; readers that also parse the page index region
+    /// attach it via [`RowGroupMetaData::set_page_indexes`].
+    column_index: Option<Vec<ColumnIndex>>,
+    /// This row group's [`OffsetIndex`], one entry per column in schema order. See
+    /// [`RowGroupMetaData::try_from_thrift`] for why it never sets this.
+    offset_index: Option<Vec<OffsetIndex>>,
+}
+
+impl RowGroupMetaData {
+    /// Creates a new [`RowGroupMetaData`] from its columns, with no page index attached.
+    pub fn new(
+        columns: Vec<ColumnChunkMetaData>,
+        num_rows: usize,
+        total_byte_size: usize,
+    ) -> Self {
+        Self {
+            columns,
+            num_rows,
+            total_byte_size,
+            column_index: None,
+            offset_index: None,
+        }
+    }
+
+    /// The column chunks of this row group.
+    pub fn columns(&self) -> &[ColumnChunkMetaData] {
+        &self.columns
+    }
+
+    /// The number of rows in this row group.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// The total byte size of all uncompressed column data in this row group.
+    pub fn total_byte_size(&self) -> usize {
+        self.total_byte_size
+    }
+
+    /// This row group's [`ColumnIndex`] per column, in schema order, if it has been read.
+    pub fn column_index(&self) -> Option<&[ColumnIndex]> {
+        self.column_index.as_deref()
+    }
+
+    /// This row group's [`OffsetIndex`] per column, in schema order, if it has been read.
+    pub fn offset_index(&self) -> Option<&[OffsetIndex]> {
+        self.offset_index.as_deref()
+    }
+
+    /// Attaches a page index to this row group, as parsed separately from the file's page index
+    /// region. This is what enables page-level predicate pushdown and row skipping for readers,
+    /// and lets metadata-only rewriters carry the page index through unchanged.
+    pub fn set_page_indexes(
+        &mut self,
+        column_index: Option<Vec<ColumnIndex>>,
+        offset_index: Option<Vec<OffsetIndex>>,
+    ) {
+        self.column_index = column_index;
+        self.offset_index = offset_index;
+    }
+
+    /// Deserializes [`parquet_format_safe::RowGroup`] into this struct.
+    ///
+    /// The page index is not part of the `RowGroup` thrift struct, so it is never set by this
+    /// constructor; call [`RowGroupMetaData::set_page_indexes`] once it has been parsed.
+    pub fn try_from_thrift(
+        schema_descr: &SchemaDescriptor,
+        row_group: TRowGroup,
+    ) -> Result<Self, Error> {
+        let columns = row_group
+            .columns
+            .into_iter()
+            .zip(schema_descr.columns())
+            .map(|(column_chunk, descriptor)| {
+                ColumnChunkMetaData::try_from_thrift(descriptor.clone(), column_chunk)
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self {
+            columns,
+            num_rows: row_group.num_rows.try_into()?,
+            total_byte_size: row_group.total_byte_size.try_into()?,
+            column_index: None,
+            offset_index: None,
+        })
+    }
+
+    /// Serializes itself to thrift's [`parquet_format_safe::RowGroup`].
+    ///
+    /// The page index attached via [`RowGroupMetaData::set_page_indexes`], if any, is not part
+    /// of the `RowGroup` thrift struct and so isn't serialized here; callers that rewrite the
+    /// page index region are responsible for writing it separately and updating the
+    /// corresponding offset/length fields on each column chunk.
+    pub fn into_thrift(self) -> TRowGroup {
+        TRowGroup {
+            columns: self
+                .columns
+                .into_iter()
+                .map(ColumnChunkMetaData::into_thrift)
+                .collect(),
+            total_byte_size: self.total_byte_size as i64,
+            num_rows: self.num_rows as i64,
+            sorting_columns: None,
+            file_offset: None,
+            total_compressed_size: None,
+            ordinal: None,
+        }
+    }
+}